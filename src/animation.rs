@@ -0,0 +1,79 @@
+use std::time::Duration;
+
+use crate::{bit_index, check_bounds, CubePosition};
+
+/// A single frame of an animation: a full snapshot of an `N`×`N`×`N` cube's LED state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame<const N: usize> {
+    state: Vec<u8>,
+}
+
+impl<const N: usize> Frame<N> {
+    /// Creates a blank frame with every LED off.
+    pub fn new() -> Self {
+        Frame {
+            state: vec![0; N * N],
+        }
+    }
+
+    /// Turn the LED at position `pos` on or off based on `state`.
+    ///
+    /// # Panics
+    /// Panics if `pos` is out of range.
+    pub fn set(&mut self, pos: CubePosition, state: bool) {
+        check_bounds::<N>(pos).unwrap();
+        let (idx, mask) = bit_index::<N>(pos);
+        if state {
+            self.state[idx] |= mask;
+        } else {
+            self.state[idx] &= !mask;
+        }
+    }
+
+    /// Returns `true` if the LED at position `pos` is on.
+    ///
+    /// # Panics
+    /// Panics if `pos` is out of range.
+    pub fn get(&self, pos: CubePosition) -> bool {
+        check_bounds::<N>(pos).unwrap();
+        let (idx, mask) = bit_index::<N>(pos);
+        self.state[idx] & mask != 0
+    }
+
+    pub(crate) fn state(&self) -> &[u8] {
+        &self.state
+    }
+}
+
+impl<const N: usize> Default for Frame<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An animation: an ordered sequence of frames, each held on the cube for a fixed duration.
+///
+/// Build one with [`Animation::push`], then play it back with
+/// [`Cube::play`](crate::Cube::play) or [`Cube::play_async`](crate::Cube::play_async).
+#[derive(Debug, Clone, Default)]
+pub struct Animation<const N: usize> {
+    frames: Vec<(Frame<N>, Duration)>,
+}
+
+impl<const N: usize> Animation<N> {
+    /// Creates an empty animation.
+    pub fn new() -> Self {
+        Animation { frames: Vec::new() }
+    }
+
+    /// Appends `frame` to the end of the animation, to be held for `duration` during playback.
+    pub fn push(&mut self, frame: Frame<N>, duration: Duration) -> &mut Self {
+        self.frames.push((frame, duration));
+        self
+    }
+
+    /// Returns the frames making up this animation, in playback order.
+    pub fn frames(&self) -> &[(Frame<N>, Duration)] {
+        &self.frames
+    }
+}
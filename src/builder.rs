@@ -0,0 +1,99 @@
+use std::ffi::OsStr;
+use std::time::Duration;
+
+use serial::SerialPort;
+
+use crate::{Cube, CubeError};
+
+/// A builder for configuring and opening a connection to an LED cube.
+///
+/// By default, the serial port is opened with whatever defaults the `serial` crate and the
+/// underlying OS provide, and the cube is cleared as soon as the connection is established.
+///
+/// # Examples
+///
+/// ```no_run
+/// use led_cube::{Cube4, CubeBuilder};
+/// use serial::BaudRate;
+/// use std::time::Duration;
+///
+/// let cube: Cube4 = CubeBuilder::new()
+///     .baud_rate(BaudRate::Baud115200)
+///     .timeout(Duration::from_millis(500))
+///     .open("COM5")
+///     .unwrap();
+/// ```
+pub struct CubeBuilder<const N: usize> {
+    baud_rate: Option<serial::BaudRate>,
+    timeout: Option<Duration>,
+    clear_on_connect: bool,
+    delta_capable: bool,
+}
+
+impl<const N: usize> CubeBuilder<N> {
+    /// Creates a new builder with the default settings.
+    pub fn new() -> Self {
+        CubeBuilder {
+            baud_rate: None,
+            timeout: None,
+            clear_on_connect: true,
+            delta_capable: false,
+        }
+    }
+
+    /// Sets the baud rate to use for the serial connection.
+    pub fn baud_rate(mut self, baud_rate: serial::BaudRate) -> Self {
+        self.baud_rate = Some(baud_rate);
+        self
+    }
+
+    /// Sets the read/write timeout to use for the serial connection.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether the cube should be cleared as soon as the connection is opened.
+    ///
+    /// Defaults to `true`.
+    pub fn clear_on_connect(mut self, clear_on_connect: bool) -> Self {
+        self.clear_on_connect = clear_on_connect;
+        self
+    }
+
+    /// Declares that the cube's firmware understands the addressed-write opcode used by
+    /// [`Cube::flush_delta`](crate::Cube::flush_delta).
+    ///
+    /// Defaults to `false`. Only set this if the firmware was built with delta-write support;
+    /// otherwise the cube won't understand the commands `flush_delta` sends.
+    pub fn delta_capable(mut self, delta_capable: bool) -> Self {
+        self.delta_capable = delta_capable;
+        self
+    }
+
+    /// Opens the serial port with the configured settings and connects to the cube.
+    pub fn open<T: AsRef<OsStr> + ?Sized>(self, port: &T) -> Result<Cube<N>, CubeError> {
+        let mut serial_port = serial::open(port)?;
+
+        if let Some(baud_rate) = self.baud_rate {
+            serial_port.reconfigure(&|settings| settings.set_baud_rate(baud_rate))?;
+        }
+
+        if let Some(timeout) = self.timeout {
+            serial_port.set_timeout(timeout)?;
+        }
+
+        let mut cube = Cube::from_port(serial_port, self.delta_capable);
+        if self.clear_on_connect {
+            cube.flush()?;
+        }
+
+        Ok(cube)
+    }
+}
+
+impl<const N: usize> Default for CubeBuilder<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,95 @@
+use std::io::Write;
+
+use crate::{Cube, CubeError};
+
+/// Opcode identifying an addressed (delta) write in the wire protocol, as opposed to a full-frame
+/// write. Only understood by firmware built with delta-write support; see
+/// [`CubeBuilder::delta_capable`](crate::CubeBuilder::delta_capable).
+const DELTA_WRITE_OPCODE: u8 = 0xff;
+
+/// Bytes used to encode a single addressed write: opcode, column index, and value.
+const DELTA_COMMAND_BYTES: usize = 3;
+
+impl<const N: usize> Cube<N> {
+    /// Update the LED cube to match the internal buffer, sending only the columns that changed
+    /// since the last flush.
+    ///
+    /// Falls back to a full [`flush`](Cube::flush) if the cube wasn't opened with
+    /// [`CubeBuilder::delta_capable`](crate::CubeBuilder::delta_capable), or if the delta would be
+    /// at least as large as just sending the whole buffer.
+    pub fn flush_delta(&mut self) -> Result<(), CubeError> {
+        if !self.delta_capable {
+            return self.flush();
+        }
+
+        let changes = changed_bytes(&self.state, &self.shadow);
+
+        if is_full_flush_smaller(changes.len(), self.state.len()) {
+            return self.flush();
+        }
+
+        for (idx, value) in changes {
+            self.port
+                .lock()
+                .unwrap()
+                .write_all(&[DELTA_WRITE_OPCODE, idx as u8, value])?;
+            self.shadow[idx] = value;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the `(index, value)` pairs where `state` differs from `shadow`.
+fn changed_bytes(state: &[u8], shadow: &[u8]) -> Vec<(usize, u8)> {
+    state
+        .iter()
+        .zip(shadow.iter())
+        .enumerate()
+        .filter_map(|(idx, (&value, &prev))| (value != prev).then_some((idx, value)))
+        .collect()
+}
+
+/// Returns `true` if sending `changes_len` addressed-write commands would be at least as large
+/// as just sending the whole `buffer_len`-byte frame.
+fn is_full_flush_smaller(changes_len: usize, buffer_len: usize) -> bool {
+    changes_len * DELTA_COMMAND_BYTES >= buffer_len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn changed_bytes_finds_no_changes_when_equal() {
+        assert_eq!(changed_bytes(&[1, 2, 3], &[1, 2, 3]), vec![]);
+    }
+
+    #[test]
+    fn changed_bytes_finds_every_differing_index() {
+        assert_eq!(
+            changed_bytes(&[1, 9, 3, 9], &[1, 2, 3, 4]),
+            vec![(1, 9), (3, 9)]
+        );
+    }
+
+    #[test]
+    fn is_full_flush_smaller_prefers_delta_when_few_changes() {
+        // 1 change * 3 bytes/command = 3, well under a 16-byte frame.
+        assert!(!is_full_flush_smaller(1, 16));
+    }
+
+    #[test]
+    fn is_full_flush_smaller_falls_back_when_every_column_changed() {
+        // 16 changes * 3 bytes/command = 48 >= 16-byte frame: not worth it.
+        assert!(is_full_flush_smaller(16, 16));
+    }
+
+    #[test]
+    fn is_full_flush_smaller_falls_back_just_past_the_threshold() {
+        // 6 changes * 3 = 18 >= a 16-byte frame, so full flush is smaller (or equal).
+        assert!(is_full_flush_smaller(6, 16));
+        // 5 changes * 3 = 15 < 16, so the delta is still smaller.
+        assert!(!is_full_flush_smaller(5, 16));
+    }
+}
@@ -0,0 +1,52 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use crate::CubePosition;
+
+/// The error type for operations on a [`Cube`](crate::Cube).
+#[derive(Debug)]
+pub enum CubeError {
+    /// An error occurred while opening or configuring the serial port.
+    Serial(serial::Error),
+
+    /// An I/O error occurred while communicating with the cube.
+    Io(io::Error),
+
+    /// The given position lies outside the bounds of the cube.
+    OutOfBounds(CubePosition),
+}
+
+impl fmt::Display for CubeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CubeError::Serial(ref e) => write!(f, "serial port error: {}", e),
+            CubeError::Io(ref e) => write!(f, "I/O error: {}", e),
+            CubeError::OutOfBounds(pos) => {
+                write!(f, "position {:?} is out of bounds for this cube", pos)
+            }
+        }
+    }
+}
+
+impl Error for CubeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            CubeError::Serial(ref e) => Some(e),
+            CubeError::Io(ref e) => Some(e),
+            CubeError::OutOfBounds(_) => None,
+        }
+    }
+}
+
+impl From<serial::Error> for CubeError {
+    fn from(e: serial::Error) -> Self {
+        CubeError::Serial(e)
+    }
+}
+
+impl From<io::Error> for CubeError {
+    fn from(e: io::Error) -> Self {
+        CubeError::Io(e)
+    }
+}
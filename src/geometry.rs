@@ -0,0 +1,176 @@
+use crate::{Cube, CubePosition};
+
+/// One of the three coordinate axes of a cube, used by [`Cube::set_plane`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl<const N: usize> Cube<N> {
+    /// Draws a line between `a` and `b` using a 3D Bresenham walk.
+    ///
+    /// # Panics
+    /// Panics if `a` or `b` is out of range.
+    pub fn set_line(&mut self, a: CubePosition, b: CubePosition, state: bool) {
+        for pos in bresenham_points(a, b) {
+            self.set(pos, state);
+        }
+    }
+
+    /// Turns every LED in the x/y/z slice at `index` along `axis` on or off.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of range.
+    pub fn set_plane(&mut self, axis: Axis, index: usize, state: bool) {
+        for u in 0..N {
+            for v in 0..N {
+                let pos = match axis {
+                    Axis::X => [index, u, v],
+                    Axis::Y => [u, index, v],
+                    Axis::Z => [u, v, index],
+                };
+                self.set(pos, state);
+            }
+        }
+    }
+
+    /// Turns every LED in the cube on or off.
+    ///
+    /// This is the complement of [`clear`](Cube::clear), which always turns every LED off.
+    pub fn fill(&mut self, state: bool) {
+        for x in 0..N {
+            for y in 0..N {
+                for z in 0..N {
+                    self.set([x, y, z], state);
+                }
+            }
+        }
+    }
+
+    /// Turns every LED in the axis-aligned box starting at `corner` and extending by `size` on
+    /// each axis on or off.
+    ///
+    /// # Panics
+    /// Panics if any LED in the box is out of range.
+    pub fn set_cube(&mut self, corner: CubePosition, size: CubePosition, state: bool) {
+        for x in corner[0]..corner[0] + size[0] {
+            for y in corner[1]..corner[1] + size[1] {
+                for z in corner[2]..corner[2] + size[2] {
+                    self.set([x, y, z], state);
+                }
+            }
+        }
+    }
+}
+
+/// Returns an iterator over the lattice points visited by a 3D Bresenham walk from `a` to `b`,
+/// inclusive of both endpoints, stepping along the dominant axis and carrying error accumulators
+/// for the other two.
+fn bresenham_points(a: CubePosition, b: CubePosition) -> impl Iterator<Item = CubePosition> {
+    let a = [a[0] as isize, a[1] as isize, a[2] as isize];
+    let b = [b[0] as isize, b[1] as isize, b[2] as isize];
+
+    let delta = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+    let abs_delta = [delta[0].abs(), delta[1].abs(), delta[2].abs()];
+    let step = [delta[0].signum(), delta[1].signum(), delta[2].signum()];
+
+    let dominant = if abs_delta[0] >= abs_delta[1] && abs_delta[0] >= abs_delta[2] {
+        0
+    } else if abs_delta[1] >= abs_delta[2] {
+        1
+    } else {
+        2
+    };
+    let dominant_delta = abs_delta[dominant];
+
+    let mut pos = a;
+    let mut err = [dominant_delta / 2; 3];
+    let mut done = false;
+
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+
+        let current = [pos[0] as usize, pos[1] as usize, pos[2] as usize];
+
+        if pos[dominant] == b[dominant] {
+            done = true;
+        } else {
+            pos[dominant] += step[dominant];
+
+            for axis in 0..3 {
+                if axis == dominant {
+                    continue;
+                }
+                err[axis] -= abs_delta[axis];
+                if err[axis] < 0 {
+                    pos[axis] += step[axis];
+                    err[axis] += dominant_delta;
+                }
+            }
+        }
+
+        Some(current)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bresenham_single_point() {
+        assert_eq!(
+            bresenham_points([1, 2, 3], [1, 2, 3]).collect::<Vec<_>>(),
+            vec![[1, 2, 3]]
+        );
+    }
+
+    #[test]
+    fn bresenham_axis_aligned_line() {
+        assert_eq!(
+            bresenham_points([0, 0, 0], [3, 0, 0]).collect::<Vec<_>>(),
+            vec![[0, 0, 0], [1, 0, 0], [2, 0, 0], [3, 0, 0]]
+        );
+    }
+
+    #[test]
+    fn bresenham_face_diagonal() {
+        assert_eq!(
+            bresenham_points([0, 0, 0], [3, 3, 0]).collect::<Vec<_>>(),
+            vec![[0, 0, 0], [1, 1, 0], [2, 2, 0], [3, 3, 0]]
+        );
+    }
+
+    #[test]
+    fn bresenham_space_diagonal() {
+        assert_eq!(
+            bresenham_points([0, 0, 0], [3, 3, 3]).collect::<Vec<_>>(),
+            vec![[0, 0, 0], [1, 1, 1], [2, 2, 2], [3, 3, 3]]
+        );
+    }
+
+    #[test]
+    fn bresenham_uneven_slope_walks_every_lattice_point_on_dominant_axis() {
+        // Dominant axis (x) has 7 steps; y and z should fall behind and catch up without
+        // skipping or repeating an x value.
+        let points: Vec<_> = bresenham_points([0, 0, 0], [7, 2, 1]).collect();
+        let xs: Vec<usize> = points.iter().map(|p| p[0]).collect();
+        assert_eq!(xs, (0..=7).collect::<Vec<_>>());
+        assert_eq!(points[0], [0, 0, 0]);
+        assert_eq!(points[points.len() - 1], [7, 2, 1]);
+    }
+
+    #[test]
+    fn bresenham_is_symmetric_in_reverse() {
+        let mut forward: Vec<_> = bresenham_points([0, 0, 0], [5, 3, 1]).collect();
+        let mut backward: Vec<_> = bresenham_points([5, 3, 1], [0, 0, 0]).collect();
+        backward.reverse();
+        forward.sort();
+        backward.sort();
+        assert_eq!(forward, backward);
+    }
+}
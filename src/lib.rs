@@ -1,17 +1,30 @@
 extern crate serial;
 
+mod animation;
+mod builder;
+mod delta;
+mod error;
+mod geometry;
+
 use std::ffi::OsStr;
-use std::io;
 use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+pub use animation::{Animation, Frame};
+pub use builder::CubeBuilder;
+pub use error::CubeError;
+pub use geometry::Axis;
 
 /// A type representing a position on the cube, of the form [`x`, `y`, `z`].
 pub type CubePosition = [usize; 3];
 
-/// A connection to an LED cube.
+/// A connection to an LED cube of size `N` × `N` × `N`.
 ///
 /// The methods on this struct operate on an internal buffer, and won't have an effect on the cube
 /// until the `flush` method is called.
 ///
+/// `N` must not exceed 8, since each column of the cube is packed into a single byte.
+///
 /// # Examples
 ///
 /// Turn each light on in sequence:
@@ -21,7 +34,7 @@ pub type CubePosition = [usize; 3];
 /// use std::time::Duration;
 /// use led_cube::*;
 ///
-/// let mut cube = Cube::new("COM5").unwrap();
+/// let mut cube = Cube4::new("COM5").unwrap();
 ///
 /// for z in 0..4 {
 ///     for y in 0..4 {
@@ -34,28 +47,60 @@ pub type CubePosition = [usize; 3];
 ///     }
 /// }
 /// ```
-pub struct Cube {
-    state: [u8; 16],
-    port: serial::SystemPort,
+pub struct Cube<const N: usize> {
+    // One byte per column (`N * N` of them); the bit pattern runs across the x-axis. A `Vec` is
+    // used here rather than `[u8; N * N]` since expressions in const-generic array lengths aren't
+    // supported on stable Rust.
+    state: Vec<u8>,
+    // `Arc<Mutex<_>>` so `flush_async` can hand a clone to a blocking-pool thread without taking
+    // the port away from `self` for the duration of the `await` — if the returned future is
+    // dropped before the write completes, the background thread still finishes the write and
+    // releases the lock, instead of leaving the cube permanently without a port.
+    port: Arc<Mutex<serial::SystemPort>>,
+    // Mirrors the state as of the last successful flush, so `flush_delta` can tell which columns
+    // changed.
+    shadow: Vec<u8>,
+    delta_capable: bool,
 }
 
-impl Cube {
-    /// Connect to an LED cube through a serial port.
-    pub fn new<T: AsRef<OsStr> + ?Sized>(port: &T) -> io::Result<Self> {
-        let mut cube = Cube {
-            state: [0b0000, 0b0000, 0b0000, 0b0000,
-                    0b0000, 0b0000, 0b0000, 0b0000,
-                    0b0000, 0b0000, 0b0000, 0b0000,
-                    0b0000, 0b0000, 0b0000, 0b0000],
-            port: serial::open(port).unwrap(),
-        };
-        try!(cube.flush());
-        Result::Ok(cube)
+/// A connection to the original 4×4×4 LED cube.
+pub type Cube4 = Cube<4>;
+
+impl<const N: usize> Cube<N> {
+    // Forces a compile-time failure for any `N` that won't fit in the single-byte column
+    // encoding `bit_index` relies on. Referenced from every constructor.
+    const ASSERT_N_FITS_IN_BYTE: () = assert!(
+        N <= 8,
+        "Cube only supports N <= 8, since each column is packed into a single byte"
+    );
+
+    /// Connect to an LED cube through a serial port, using the default serial settings.
+    ///
+    /// To customize the baud rate, timeout, or initial-clear behavior, use [`CubeBuilder`]
+    /// instead.
+    pub fn new<T: AsRef<OsStr> + ?Sized>(port: &T) -> Result<Self, CubeError> {
+        CubeBuilder::new().open(port)
+    }
+
+    pub(crate) fn from_port(port: serial::SystemPort, delta_capable: bool) -> Self {
+        // The path expression alone forces evaluation of the const (and thus the assertion) at
+        // monomorphization time; it has no runtime effect, which is exactly what we want here.
+        #[allow(path_statements)]
+        Self::ASSERT_N_FITS_IN_BYTE;
+
+        Cube {
+            state: vec![0; N * N],
+            port: Arc::new(Mutex::new(port)),
+            shadow: vec![0; N * N],
+            delta_capable,
+        }
     }
 
     /// Update the LED cube to match the internal buffer.
-    pub fn flush(&mut self) -> io::Result<()>{
-        self.port.write(&self.state).map(|_| {})
+    pub fn flush(&mut self) -> Result<(), CubeError> {
+        self.port.lock().unwrap().write_all(&self.state)?;
+        self.shadow.copy_from_slice(&self.state);
+        Ok(())
     }
 
     /// Turn the LED at position `pos` on or off based on `state`.
@@ -63,14 +108,18 @@ impl Cube {
     /// # Panics
     /// Panics if `pos` is out of range.
     pub fn set(&mut self, pos: CubePosition, state: bool) {
-        check_bounds(pos);
-        let pattern_idx = 4 * invert4(pos[1]) + invert4(pos[2]);
-        let mask = 1 << invert4(pos[0]);
-        if state {
-            self.state[pattern_idx] |= mask;
-        } else {
-            self.state[pattern_idx] &= !mask;
-        }
+        check_bounds::<N>(pos).unwrap();
+        self.set_unchecked(pos, state);
+    }
+
+    /// Turn the LED at position `pos` on or off based on `state`.
+    ///
+    /// Unlike [`set`](Cube::set), this returns an error instead of panicking if `pos` is out of
+    /// range.
+    pub fn try_set(&mut self, pos: CubePosition, state: bool) -> Result<(), CubeError> {
+        check_bounds::<N>(pos)?;
+        self.set_unchecked(pos, state);
+        Ok(())
     }
 
     /// Returns `true` if the LED at position `pos` is on.
@@ -78,23 +127,101 @@ impl Cube {
     /// # Panics
     /// Panics if `pos` is out of range.
     pub fn get(&self, pos: CubePosition) -> bool {
-        check_bounds(pos);
-        self.state[4 * invert4(pos[1]) + invert4(pos[2])] & (1 << invert4(pos[0])) != 0
+        check_bounds::<N>(pos).unwrap();
+        self.get_unchecked(pos)
+    }
+
+    /// Returns `true` if the LED at position `pos` is on.
+    ///
+    /// Unlike [`get`](Cube::get), this returns an error instead of panicking if `pos` is out of
+    /// range.
+    pub fn try_get(&self, pos: CubePosition) -> Result<bool, CubeError> {
+        check_bounds::<N>(pos)?;
+        Ok(self.get_unchecked(pos))
     }
 
     /// Turns off all LEDs.
     pub fn clear(&mut self) {
-        self.state = [0b0000, 0b0000, 0b0000, 0b0000,
-                      0b0000, 0b0000, 0b0000, 0b0000,
-                      0b0000, 0b0000, 0b0000, 0b0000,
-                      0b0000, 0b0000, 0b0000, 0b0000];
+        self.state = vec![0; N * N];
+    }
+
+    /// Plays back an [`Animation`], blocking the current thread between frames.
+    pub fn play(&mut self, animation: &Animation<N>) -> Result<(), CubeError> {
+        use std::thread;
+
+        for (frame, duration) in animation.frames() {
+            self.state.copy_from_slice(frame.state());
+            self.flush()?;
+            thread::sleep(*duration);
+        }
+
+        Ok(())
+    }
+
+    fn set_unchecked(&mut self, pos: CubePosition, state: bool) {
+        let (idx, mask) = bit_index::<N>(pos);
+        if state {
+            self.state[idx] |= mask;
+        } else {
+            self.state[idx] &= !mask;
+        }
+    }
+
+    fn get_unchecked(&self, pos: CubePosition) -> bool {
+        let (idx, mask) = bit_index::<N>(pos);
+        self.state[idx] & mask != 0
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<const N: usize> Cube<N> {
+    /// Update the LED cube to match the internal buffer, without blocking the calling thread.
+    ///
+    /// The `serial` crate has no non-blocking I/O mode, so this offloads the write to a
+    /// dedicated blocking-pool thread via `blocking::unblock` rather than running it inline on
+    /// the async task.
+    ///
+    /// This is behind the `futures` feature flag.
+    pub async fn flush_async(&mut self) -> Result<(), CubeError> {
+        let port = Arc::clone(&self.port);
+        let state = self.state.clone();
+
+        blocking::unblock(move || port.lock().unwrap().write_all(&state)).await?;
+
+        self.shadow.copy_from_slice(&self.state);
+
+        Ok(())
+    }
+
+    /// Plays back an [`Animation`], sleeping between frames without blocking the current thread.
+    ///
+    /// This is behind the `futures` feature flag.
+    pub async fn play_async(&mut self, animation: &Animation<N>) -> Result<(), CubeError> {
+        for (frame, duration) in animation.frames() {
+            self.state.copy_from_slice(frame.state());
+            self.flush_async().await?;
+            futures_timer::Delay::new(*duration).await;
+        }
+
+        Ok(())
+    }
+}
+
+fn check_bounds<const N: usize>(pos: CubePosition) -> Result<(), CubeError> {
+    if pos[0] < N && pos[1] < N && pos[2] < N {
+        Ok(())
+    } else {
+        Err(CubeError::OutOfBounds(pos))
     }
 }
 
-fn check_bounds(pos: CubePosition) {
-    assert!(pos[0] < 4 && pos[1] < 4 && pos[2] < 4);
+fn invertn<const N: usize>(n: usize) -> usize {
+    N - 1 - n
 }
 
-fn invert4(n: usize) -> usize {
-    [3, 2, 1, 0][n]
+/// Returns the `(byte index, bit mask)` pair identifying `pos` within a cube's state buffer.
+fn bit_index<const N: usize>(pos: CubePosition) -> (usize, u8) {
+    let idx = N * invertn::<N>(pos[1]) + invertn::<N>(pos[2]);
+    let mask = 1 << invertn::<N>(pos[0]);
+    (idx, mask)
 }